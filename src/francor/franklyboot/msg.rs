@@ -1,6 +1,34 @@
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// Errors -----------------------------------------------------------------------------------------
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum MsgError {
+    UnknownRequestType(u16), //< Request ID is not a known request type
+    UnknownResponseType(u8), //< Response ID is not a known response type
+    InvalidVendorRequestId(u16), //< Request ID is outside of the vendor request range
+}
+
+impl core::fmt::Display for MsgError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MsgError::UnknownRequestType(value) => write!(f, "Unknown request type: {}", value),
+            MsgError::UnknownResponseType(value) => write!(f, "Unknown response type: {}", value),
+            MsgError::InvalidVendorRequestId(value) => {
+                write!(f, "Invalid vendor request ID: {}", value)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MsgError {}
+
 // Node ID ----------------------------------------------------------------------------------------
 #[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum NodeID {
     Broadcast,
     Specific(u8),
@@ -8,7 +36,45 @@ pub enum NodeID {
 
 // Request Type -----------------------------------------------------------------------------------
 
+/// First request ID reserved for vendor specific bootloader commands
+pub const REQ_VENDOR_ID_FIRST: u16 = 0xE000;
+
+/// Last request ID reserved for vendor specific bootloader commands
+pub const REQ_VENDOR_ID_LAST: u16 = 0xEFFF;
+
+/// Request ID of a vendor specific request
+///
+/// Can only be constructed from IDs in range `REQ_VENDOR_ID_FIRST..=REQ_VENDOR_ID_LAST`.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u16", into = "u16"))]
+pub struct VendorRequestId(u16);
+
+impl VendorRequestId {
+    pub fn to_u16(&self) -> u16 {
+        self.0
+    }
+}
+
+impl TryFrom<u16> for VendorRequestId {
+    type Error = MsgError;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        match value {
+            REQ_VENDOR_ID_FIRST..=REQ_VENDOR_ID_LAST => Ok(VendorRequestId(value)),
+            _ => Err(MsgError::InvalidVendorRequestId(value)),
+        }
+    }
+}
+
+impl From<VendorRequestId> for u16 {
+    fn from(id: VendorRequestId) -> u16 {
+        id.0
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum RequestType {
     ReqPing, //< Ping device | Response is bootloader version
     ReqResetDevice, //< Resets the device (hardware reset)
@@ -45,38 +111,12 @@ pub enum RequestType {
     /* Flash Write Commands*/
     ReqFlashWriteErasePage, //< Erases an flash page
     ReqFlashWriteAppCRC, //< Writes the CRC of the app to the flash
+
+    /* Vendor Commands */
+    ReqVendor(VendorRequestId), //< Vendor specific request
 }
 
 impl RequestType {
-    pub fn from_u16(value: u16) -> RequestType {
-        match value {
-            0x0001 => RequestType::ReqPing,
-            0x0011 => RequestType::ReqResetDevice,
-            0x0012 => RequestType::ReqStartApp,
-            0x0101 => RequestType::ReqDevInfoBootloaderVersion,
-            0x0102 => RequestType::ReqDevInfoBootloaderCRC,
-            0x0103 => RequestType::ReqDevInfoVID,
-            0x0104 => RequestType::ReqDevInfoPID,
-            0x0105 => RequestType::ReqDevInfoPRD,
-            0x0106 => RequestType::ReqDevInfoUID,
-            0x0201 => RequestType::ReqFlashInfoStartAddr,
-            0x0202 => RequestType::ReqFlashInfoPageSize,
-            0x0203 => RequestType::ReqFlashInfoNumPages,
-            0x0301 => RequestType::ReqAppInfoPageIdx,
-            0x0302 => RequestType::ReqAppInfoCRCCalc,
-            0x0303 => RequestType::ReqAppInfoCRCStrd,
-            0x0401 => RequestType::ReqFlashReadWord,
-            0x1001 => RequestType::ReqPageBufferClear,
-            0x1002 => RequestType::ReqPageBufferReadWord,
-            0x1003 => RequestType::ReqPageBufferWriteWord,
-            0x1004 => RequestType::ReqPageBufferCalcCRC,
-            0x1005 => RequestType::ReqPageBufferWriteToFlash,
-            0x1101 => RequestType::ReqFlashWriteErasePage,
-            0x1102 => RequestType::ReqFlashWriteAppCRC,
-            _ => panic!("Unknown request type: {}", value),
-        }
-    }
-
     pub fn to_u16(&self) -> u16 {
         match self {
             RequestType::ReqPing => 0x0001,
@@ -102,6 +142,43 @@ impl RequestType {
             RequestType::ReqPageBufferWriteToFlash => 0x1005,
             RequestType::ReqFlashWriteErasePage => 0x1101,
             RequestType::ReqFlashWriteAppCRC => 0x1102,
+            RequestType::ReqVendor(id) => id.to_u16(),
+        }
+    }
+}
+
+impl TryFrom<u16> for RequestType {
+    type Error = MsgError;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        match value {
+            0x0001 => Ok(RequestType::ReqPing),
+            0x0011 => Ok(RequestType::ReqResetDevice),
+            0x0012 => Ok(RequestType::ReqStartApp),
+            0x0101 => Ok(RequestType::ReqDevInfoBootloaderVersion),
+            0x0102 => Ok(RequestType::ReqDevInfoBootloaderCRC),
+            0x0103 => Ok(RequestType::ReqDevInfoVID),
+            0x0104 => Ok(RequestType::ReqDevInfoPID),
+            0x0105 => Ok(RequestType::ReqDevInfoPRD),
+            0x0106 => Ok(RequestType::ReqDevInfoUID),
+            0x0201 => Ok(RequestType::ReqFlashInfoStartAddr),
+            0x0202 => Ok(RequestType::ReqFlashInfoPageSize),
+            0x0203 => Ok(RequestType::ReqFlashInfoNumPages),
+            0x0301 => Ok(RequestType::ReqAppInfoPageIdx),
+            0x0302 => Ok(RequestType::ReqAppInfoCRCCalc),
+            0x0303 => Ok(RequestType::ReqAppInfoCRCStrd),
+            0x0401 => Ok(RequestType::ReqFlashReadWord),
+            0x1001 => Ok(RequestType::ReqPageBufferClear),
+            0x1002 => Ok(RequestType::ReqPageBufferReadWord),
+            0x1003 => Ok(RequestType::ReqPageBufferWriteWord),
+            0x1004 => Ok(RequestType::ReqPageBufferCalcCRC),
+            0x1005 => Ok(RequestType::ReqPageBufferWriteToFlash),
+            0x1101 => Ok(RequestType::ReqFlashWriteErasePage),
+            0x1102 => Ok(RequestType::ReqFlashWriteAppCRC),
+            REQ_VENDOR_ID_FIRST..=REQ_VENDOR_ID_LAST => {
+                VendorRequestId::try_from(value).map(RequestType::ReqVendor)
+            }
+            _ => Err(MsgError::UnknownRequestType(value)),
         }
     }
 }
@@ -110,6 +187,7 @@ impl RequestType {
 // Response types ---------------------------------------------------------------------------------
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ResponseType {   
     RespNone, // Unused / ignored
     RespAck, // Acknowledge
@@ -138,19 +216,23 @@ impl ResponseType {
             ResponseType::RespErrInvldArg => 0xF8,
         }
     }
+}
 
-    pub fn from_u8(value: u8) -> ResponseType {
+impl TryFrom<u8> for ResponseType {
+    type Error = MsgError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
         match value {
-            0x00 => ResponseType::RespNone,
-            0x01 => ResponseType::RespAck,
-            0xFE => ResponseType::RespErr,
-            0xFD => ResponseType::RespUnknownReq,
-            0xFC => ResponseType::RespErrNotSupported,
-            0xFB => ResponseType::RespErrCRCInvld,
-            0xFA => ResponseType::RespAckPageFull,
-            0xF9 => ResponseType::RespErrPageFull,
-            0xF8 => ResponseType::RespErrInvldArg,
-            _ => panic!("Unknown response type: {}", value),
+            0x00 => Ok(ResponseType::RespNone),
+            0x01 => Ok(ResponseType::RespAck),
+            0xFE => Ok(ResponseType::RespErr),
+            0xFD => Ok(ResponseType::RespUnknownReq),
+            0xFC => Ok(ResponseType::RespErrNotSupported),
+            0xFB => Ok(ResponseType::RespErrCRCInvld),
+            0xFA => Ok(ResponseType::RespAckPageFull),
+            0xF9 => Ok(ResponseType::RespErrPageFull),
+            0xF8 => Ok(ResponseType::RespErrInvldArg),
+            _ => Err(MsgError::UnknownResponseType(value)),
         }
     }
 }
@@ -159,7 +241,8 @@ impl ResponseType {
 
 pub type MsgData = [u8; 4];
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Msg {
     pub node_id: NodeID,
     pub request: RequestType,
@@ -168,6 +251,43 @@ pub struct Msg {
     pub data: MsgData,
 }
 
+// Version ----------------------------------------------------------------------------------------
+
+/// Bootloader version as reported by `ReqPing` and `ReqDevInfoBootloaderVersion`.
+///
+/// The version is transferred in the message data as `[major, minor, patch, 0]`.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Version {
+    pub major: u8,
+    pub minor: u8,
+    pub patch: u8,
+}
+
+impl Version {
+    pub fn new(major: u8, minor: u8, patch: u8) -> Version {
+        Version {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    pub fn from_msg_data(data: &MsgData) -> Version {
+        Version::new(data[0], data[1], data[2])
+    }
+
+    pub fn to_msg_data(&self) -> MsgData {
+        [self.major, self.minor, self.patch, 0]
+    }
+}
+
+impl core::fmt::Display for Version {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
 // Tests ------------------------------------------------------------------------------------------
 
 #[cfg(test)]
@@ -202,30 +322,66 @@ mod tests {
     }
 
     #[test]
-    fn request_type_convert_from_u16() {
-        assert_eq!(RequestType::from_u16(0x0001), RequestType::ReqPing);
-        assert_eq!(RequestType::from_u16(0x0011), RequestType::ReqResetDevice);
-        assert_eq!(RequestType::from_u16(0x0012), RequestType::ReqStartApp);
-        assert_eq!(RequestType::from_u16(0x0101), RequestType::ReqDevInfoBootloaderVersion);
-        assert_eq!(RequestType::from_u16(0x0102), RequestType::ReqDevInfoBootloaderCRC);
-        assert_eq!(RequestType::from_u16(0x0103), RequestType::ReqDevInfoVID);
-        assert_eq!(RequestType::from_u16(0x0104), RequestType::ReqDevInfoPID);
-        assert_eq!(RequestType::from_u16(0x0105), RequestType::ReqDevInfoPRD);
-        assert_eq!(RequestType::from_u16(0x0106), RequestType::ReqDevInfoUID);
-        assert_eq!(RequestType::from_u16(0x0201), RequestType::ReqFlashInfoStartAddr);
-        assert_eq!(RequestType::from_u16(0x0202), RequestType::ReqFlashInfoPageSize);
-        assert_eq!(RequestType::from_u16(0x0203), RequestType::ReqFlashInfoNumPages);
-        assert_eq!(RequestType::from_u16(0x0301), RequestType::ReqAppInfoPageIdx);
-        assert_eq!(RequestType::from_u16(0x0302), RequestType::ReqAppInfoCRCCalc);
-        assert_eq!(RequestType::from_u16(0x0303), RequestType::ReqAppInfoCRCStrd);
-        assert_eq!(RequestType::from_u16(0x0401), RequestType::ReqFlashReadWord);
-        assert_eq!(RequestType::from_u16(0x1001), RequestType::ReqPageBufferClear);
-        assert_eq!(RequestType::from_u16(0x1002), RequestType::ReqPageBufferReadWord);
-        assert_eq!(RequestType::from_u16(0x1003), RequestType::ReqPageBufferWriteWord);
-        assert_eq!(RequestType::from_u16(0x1004), RequestType::ReqPageBufferCalcCRC);
-        assert_eq!(RequestType::from_u16(0x1005), RequestType::ReqPageBufferWriteToFlash);
-        assert_eq!(RequestType::from_u16(0x1101), RequestType::ReqFlashWriteErasePage);
-        assert_eq!(RequestType::from_u16(0x1102), RequestType::ReqFlashWriteAppCRC);
+    fn request_type_convert_try_from_u16() {
+        assert_eq!(RequestType::try_from(0x0001), Ok(RequestType::ReqPing));
+        assert_eq!(RequestType::try_from(0x0011), Ok(RequestType::ReqResetDevice));
+        assert_eq!(RequestType::try_from(0x0012), Ok(RequestType::ReqStartApp));
+        assert_eq!(RequestType::try_from(0x0101), Ok(RequestType::ReqDevInfoBootloaderVersion));
+        assert_eq!(RequestType::try_from(0x0102), Ok(RequestType::ReqDevInfoBootloaderCRC));
+        assert_eq!(RequestType::try_from(0x0103), Ok(RequestType::ReqDevInfoVID));
+        assert_eq!(RequestType::try_from(0x0104), Ok(RequestType::ReqDevInfoPID));
+        assert_eq!(RequestType::try_from(0x0105), Ok(RequestType::ReqDevInfoPRD));
+        assert_eq!(RequestType::try_from(0x0106), Ok(RequestType::ReqDevInfoUID));
+        assert_eq!(RequestType::try_from(0x0201), Ok(RequestType::ReqFlashInfoStartAddr));
+        assert_eq!(RequestType::try_from(0x0202), Ok(RequestType::ReqFlashInfoPageSize));
+        assert_eq!(RequestType::try_from(0x0203), Ok(RequestType::ReqFlashInfoNumPages));
+        assert_eq!(RequestType::try_from(0x0301), Ok(RequestType::ReqAppInfoPageIdx));
+        assert_eq!(RequestType::try_from(0x0302), Ok(RequestType::ReqAppInfoCRCCalc));
+        assert_eq!(RequestType::try_from(0x0303), Ok(RequestType::ReqAppInfoCRCStrd));
+        assert_eq!(RequestType::try_from(0x0401), Ok(RequestType::ReqFlashReadWord));
+        assert_eq!(RequestType::try_from(0x1001), Ok(RequestType::ReqPageBufferClear));
+        assert_eq!(RequestType::try_from(0x1002), Ok(RequestType::ReqPageBufferReadWord));
+        assert_eq!(RequestType::try_from(0x1003), Ok(RequestType::ReqPageBufferWriteWord));
+        assert_eq!(RequestType::try_from(0x1004), Ok(RequestType::ReqPageBufferCalcCRC));
+        assert_eq!(RequestType::try_from(0x1005), Ok(RequestType::ReqPageBufferWriteToFlash));
+        assert_eq!(RequestType::try_from(0x1101), Ok(RequestType::ReqFlashWriteErasePage));
+        assert_eq!(RequestType::try_from(0x1102), Ok(RequestType::ReqFlashWriteAppCRC));
+    }
+
+    #[test]
+    fn request_type_convert_try_from_unknown() {
+        assert_eq!(RequestType::try_from(0x0000), Err(MsgError::UnknownRequestType(0x0000)));
+        assert_eq!(RequestType::try_from(0x0107), Err(MsgError::UnknownRequestType(0x0107)));
+        assert_eq!(RequestType::try_from(0xDFFF), Err(MsgError::UnknownRequestType(0xDFFF)));
+        assert_eq!(RequestType::try_from(0xF000), Err(MsgError::UnknownRequestType(0xF000)));
+    }
+
+    #[test]
+    fn request_type_convert_round_trip() {
+        for value in u16::MIN..=u16::MAX {
+            if let Ok(request) = RequestType::try_from(value) {
+                assert_eq!(request.to_u16(), value);
+            }
+        }
+    }
+
+    #[test]
+    fn request_type_convert_vendor() {
+        let first = VendorRequestId::try_from(0xE000).unwrap();
+        let last = VendorRequestId::try_from(0xEFFF).unwrap();
+
+        assert_eq!(RequestType::try_from(0xE000), Ok(RequestType::ReqVendor(first)));
+        assert_eq!(RequestType::try_from(0xEFFF), Ok(RequestType::ReqVendor(last)));
+        assert_eq!(RequestType::ReqVendor(first).to_u16(), 0xE000);
+        assert_eq!(RequestType::ReqVendor(last).to_u16(), 0xEFFF);
+    }
+
+    #[test]
+    fn vendor_request_id_try_from() {
+        assert_eq!(VendorRequestId::try_from(0xE123).map(|id| id.to_u16()), Ok(0xE123));
+        assert_eq!(VendorRequestId::try_from(0x0001), Err(MsgError::InvalidVendorRequestId(0x0001)));
+        assert_eq!(VendorRequestId::try_from(0xDFFF), Err(MsgError::InvalidVendorRequestId(0xDFFF)));
+        assert_eq!(VendorRequestId::try_from(0xF000), Err(MsgError::InvalidVendorRequestId(0xF000)));
     }
 
     #[test]
@@ -242,15 +398,163 @@ mod tests {
     }
 
     #[test]
-    fn response_convert_from_u8() {
-        assert_eq!(ResponseType::from_u8(0x00), ResponseType::RespNone);
-        assert_eq!(ResponseType::from_u8(0x01), ResponseType::RespAck);
-        assert_eq!(ResponseType::from_u8(0xFE), ResponseType::RespErr);
-        assert_eq!(ResponseType::from_u8(0xFD), ResponseType::RespUnknownReq);
-        assert_eq!(ResponseType::from_u8(0xFC), ResponseType::RespErrNotSupported);
-        assert_eq!(ResponseType::from_u8(0xFB), ResponseType::RespErrCRCInvld);
-        assert_eq!(ResponseType::from_u8(0xFA), ResponseType::RespAckPageFull);
-        assert_eq!(ResponseType::from_u8(0xF9), ResponseType::RespErrPageFull);
-        assert_eq!(ResponseType::from_u8(0xF8), ResponseType::RespErrInvldArg);
+    fn response_convert_try_from_u8() {
+        assert_eq!(ResponseType::try_from(0x00), Ok(ResponseType::RespNone));
+        assert_eq!(ResponseType::try_from(0x01), Ok(ResponseType::RespAck));
+        assert_eq!(ResponseType::try_from(0xFE), Ok(ResponseType::RespErr));
+        assert_eq!(ResponseType::try_from(0xFD), Ok(ResponseType::RespUnknownReq));
+        assert_eq!(ResponseType::try_from(0xFC), Ok(ResponseType::RespErrNotSupported));
+        assert_eq!(ResponseType::try_from(0xFB), Ok(ResponseType::RespErrCRCInvld));
+        assert_eq!(ResponseType::try_from(0xFA), Ok(ResponseType::RespAckPageFull));
+        assert_eq!(ResponseType::try_from(0xF9), Ok(ResponseType::RespErrPageFull));
+        assert_eq!(ResponseType::try_from(0xF8), Ok(ResponseType::RespErrInvldArg));
+    }
+
+    #[test]
+    fn response_convert_round_trip() {
+        for value in u8::MIN..=u8::MAX {
+            if let Ok(response) = ResponseType::try_from(value) {
+                assert_eq!(response.to_u8(), value);
+            }
+        }
+    }
+
+    #[test]
+    fn response_convert_try_from_unknown() {
+        assert_eq!(ResponseType::try_from(0x02), Err(MsgError::UnknownResponseType(0x02)));
+        assert_eq!(ResponseType::try_from(0xF7), Err(MsgError::UnknownResponseType(0xF7)));
+        assert_eq!(ResponseType::try_from(0xFF), Err(MsgError::UnknownResponseType(0xFF)));
+    }
+
+    #[test]
+    fn version_convert_from_msg_data() {
+        assert_eq!(Version::from_msg_data(&[1, 2, 3, 0]), Version::new(1, 2, 3));
+        assert_eq!(Version::from_msg_data(&[0, 0, 0, 0]), Version::new(0, 0, 0));
+        assert_eq!(Version::from_msg_data(&[255, 16, 7, 0xAA]), Version::new(255, 16, 7));
+    }
+
+    #[test]
+    fn version_convert_to_msg_data() {
+        assert_eq!(Version::new(1, 2, 3).to_msg_data(), [1, 2, 3, 0]);
+        assert_eq!(Version::new(255, 16, 7).to_msg_data(), [255, 16, 7, 0]);
+    }
+
+    #[test]
+    fn version_ordering() {
+        assert!(Version::new(1, 0, 0) > Version::new(0, 9, 9));
+        assert!(Version::new(1, 2, 0) > Version::new(1, 1, 9));
+        assert!(Version::new(1, 2, 3) > Version::new(1, 2, 2));
+        assert_eq!(Version::new(1, 2, 3), Version::new(1, 2, 3));
+    }
+
+    #[test]
+    fn version_display() {
+        assert_eq!(format!("{}", Version::new(1, 2, 3)), "1.2.3");
+        assert_eq!(format!("{}", Version::new(0, 10, 255)), "0.10.255");
+    }
+
+    #[cfg(feature = "serde")]
+    mod serde_round_trip {
+        use super::*;
+        use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+        #[test]
+        fn node_id() {
+            assert_tokens(
+                &NodeID::Broadcast,
+                &[Token::UnitVariant { name: "NodeID", variant: "Broadcast" }],
+            );
+            assert_tokens(
+                &NodeID::Specific(5),
+                &[Token::NewtypeVariant { name: "NodeID", variant: "Specific" }, Token::U8(5)],
+            );
+        }
+
+        #[test]
+        fn request_type() {
+            assert_tokens(
+                &RequestType::ReqPing,
+                &[Token::UnitVariant { name: "RequestType", variant: "ReqPing" }],
+            );
+            assert_tokens(
+                &RequestType::ReqVendor(VendorRequestId::try_from(0xE001).unwrap()),
+                &[
+                    Token::NewtypeVariant { name: "RequestType", variant: "ReqVendor" },
+                    Token::U16(0xE001),
+                ],
+            );
+        }
+
+        #[test]
+        fn request_type_vendor_out_of_range() {
+            assert_de_tokens_error::<RequestType>(
+                &[
+                    Token::NewtypeVariant { name: "RequestType", variant: "ReqVendor" },
+                    Token::U16(0x0001),
+                ],
+                "Invalid vendor request ID: 1",
+            );
+        }
+
+        #[test]
+        fn response_type() {
+            assert_tokens(
+                &ResponseType::RespErrCRCInvld,
+                &[Token::UnitVariant { name: "ResponseType", variant: "RespErrCRCInvld" }],
+            );
+        }
+
+        #[test]
+        fn msg() {
+            let msg = Msg {
+                node_id: NodeID::Specific(3),
+                request: RequestType::ReqVendor(VendorRequestId::try_from(0xEFFF).unwrap()),
+                response: ResponseType::RespAck,
+                packet_id: 7,
+                data: [0xDE, 0xAD, 0xBE, 0xEF],
+            };
+
+            assert_tokens(
+                &msg,
+                &[
+                    Token::Struct { name: "Msg", len: 5 },
+                    Token::Str("node_id"),
+                    Token::NewtypeVariant { name: "NodeID", variant: "Specific" },
+                    Token::U8(3),
+                    Token::Str("request"),
+                    Token::NewtypeVariant { name: "RequestType", variant: "ReqVendor" },
+                    Token::U16(0xEFFF),
+                    Token::Str("response"),
+                    Token::UnitVariant { name: "ResponseType", variant: "RespAck" },
+                    Token::Str("packet_id"),
+                    Token::U8(7),
+                    Token::Str("data"),
+                    Token::Tuple { len: 4 },
+                    Token::U8(0xDE),
+                    Token::U8(0xAD),
+                    Token::U8(0xBE),
+                    Token::U8(0xEF),
+                    Token::TupleEnd,
+                    Token::StructEnd,
+                ],
+            );
+        }
+
+        #[test]
+        fn version() {
+            assert_tokens(
+                &Version::new(1, 2, 3),
+                &[
+                    Token::Struct { name: "Version", len: 3 },
+                    Token::Str("major"),
+                    Token::U8(1),
+                    Token::Str("minor"),
+                    Token::U8(2),
+                    Token::Str("patch"),
+                    Token::U8(3),
+                    Token::StructEnd,
+                ],
+            );
+        }
     }
 }
\ No newline at end of file
@@ -1,2 +1,3 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![allow(dead_code)]
 pub mod francor;
\ No newline at end of file